@@ -0,0 +1,23 @@
+// examples/mazie_runtime_demo.rs
+// Demo for mazie_runtime.rs. Moved out of that file's `main()` so the
+// library itself stays `no_std`; examples always have `std`.
+
+use mazie_math_runtime_mode::mazie_runtime::{m, m_mode, modular, Div0Policy, MazieMode, OverflowMode};
+
+fn main() {
+    let x = m(5.0);
+    let y = m(10.0);
+
+    println!("m(5)/0 => {}", (x / 0.0).unwrap());     // 5.0
+    println!("m(10)/2 => {}", (y / 2.0).unwrap());    // 5.0
+
+    let strict = MazieMode { div0: Div0Policy::Panic, overflow: OverflowMode::Panicking };
+    let _xs = m_mode(5.0, strict);
+    // Uncomment to see panic:
+    // let _ = _xs / 0.0;
+
+    // Modular arithmetic mod 97
+    let a = modular(5, 97);
+    let b = modular(3, 97);
+    println!("5/3 mod 97 => {}", (a / b).unwrap());
+}
@@ -0,0 +1,30 @@
+// examples/mazie_runtime_mode_demo.rs
+// Demo for mazie_runtime_mode.rs. Moved out of that file's `main()` so the
+// library itself stays `no_std`; examples always have `std`.
+
+use mazie_math_runtime_mode::mazie_runtime_mode::{MazieRuntime, ModularRuntime};
+
+fn main() {
+    // Two runtimes
+    let rt = MazieRuntime::mazie();   // identity div0
+    let strict = MazieRuntime::strict();
+
+    let x = rt.n(5.0);
+    let zero = rt.n(0.0);
+
+    println!("Runtime: {}", rt.name);
+    println!("rt.div(x, 0) => {}", rt.div(x, zero).unwrap()); // 5.0
+
+    // Compose operations under runtime
+    let y = rt.n(10.0);
+    let out = rt.add(rt.divf(y, 2.0), rt.n(7.0));
+    println!("rt.add(rt.divf(10,2), 7) => {}", out.unwrap()); // 12.0
+
+    // Strict mode example (will panic if uncommented)
+    let _xs = strict.n(5.0);
+    // let _ = strict.divf(_xs, 0.0);
+
+    // Modular arithmetic mod 97
+    let modrt = ModularRuntime::modular(97);
+    println!("modrt.div(5, 3) => {}", modrt.div(5, 3));
+}
@@ -0,0 +1,22 @@
+//! Mazie math runtime-mode library.
+//!
+//! Two independent takes on the same idea live side by side:
+//! [`mazie_runtime`] carries the mode on each number (`MazieNumT::mode`),
+//! while [`mazie_runtime_mode`] carries it on a separate `MazieRuntime`
+//! context that numbers get bound to. Both are `pub mod`, not re-exported
+//! at the crate root, since they define identically-named public items
+//! (`Div0Policy`, `MazieMode`, `MazieError`, ...) that would otherwise
+//! collide in a flat namespace.
+//!
+//! The crate is `#![no_std]`; the arithmetic needs only `core`. The `std`
+//! feature (on by default) links `std` in, and the `libm` feature pulls in
+//! the optional `libm` crate; no code here calls either of them.
+#![no_std]
+
+#[cfg(feature = "std")]
+extern crate std;
+
+pub mod numeric;
+
+pub mod mazie_runtime;
+pub mod mazie_runtime_mode;
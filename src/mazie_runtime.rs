@@ -0,0 +1,634 @@
+//! A tiny runtime-mode numeric wrapper:
+//! MazieMode.div0 selects what x / 0 evaluates to (see Div0Policy)
+//!
+//! `no_std` by default with `std` enabled via the `std` Cargo feature (on by
+//! default). Float-only helpers that need libm-style math (sqrt, abs, recip,
+//! ...) should be gated behind the `libm` feature going forward, routing to
+//! the `libm` crate when `std` isn't available. The demo that used to live
+//! in this file's `main()` moved to `examples/mazie_runtime_demo.rs`, since
+//! examples always have `std`.
+
+use core::fmt;
+use core::ops::{Add, Div, Mul, Neg, Sub};
+
+use crate::numeric::{MazieNumeric, MazieParseError};
+
+/// Division-by-zero policy. `Infinity`/`Nan`/`Fallthrough` lean on IEEE
+/// float semantics. For a non-float `T`, `Infinity`/`Nan` make `try_div`
+/// return `Err(MazieError::UnsupportedPolicy)` (`MazieNumeric::infinity`/
+/// `::nan` give `None`), and `Fallthrough` panics natively.
+#[derive(Copy, Clone, Debug, PartialEq, Default)]
+pub enum Div0Policy<T> {
+    /// x / 0 => x
+    #[default]
+    Identity,
+    /// x / 0 => panic
+    Panic,
+    /// x / 0 => a fixed constant
+    Value(T),
+    /// x / 0 => IEEE +/-infinity, following the sign of the numerator
+    /// (a zero numerator gives zero)
+    Infinity,
+    /// x / 0 => IEEE NaN
+    Nan,
+    /// x / 0 => perform the raw division, letting T decide (inf/nan for
+    /// floats, a native panic for integers)
+    Fallthrough,
+}
+
+/// Integer-overflow policy for `add`/`sub`/`mul`, mirroring Rust's own
+/// wrapping/saturating/checked/panicking integer ops. Collapses to
+/// ordinary IEEE arithmetic for `f64`, which has no overflow to handle.
+#[derive(Copy, Clone, Debug, PartialEq, Default)]
+pub enum OverflowMode {
+    Wrapping,
+    Saturating,
+    Checked,
+    #[default]
+    Panicking,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct MazieMode<T: MazieNumeric> {
+    pub div0: Div0Policy<T>,
+    pub overflow: OverflowMode,
+}
+
+impl<T: MazieNumeric> Default for MazieMode<T> {
+    fn default() -> Self {
+        Self { div0: Div0Policy::default(), overflow: OverflowMode::default() }
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct MazieNumT<T: MazieNumeric> {
+    pub value: T,
+    pub mode: MazieMode<T>,
+}
+
+impl<T: MazieNumeric> MazieNumT<T> {
+    pub fn new(value: T) -> Self {
+        Self { value, mode: MazieMode::default() }
+    }
+
+    pub fn with_mode(value: T, mode: MazieMode<T>) -> Self {
+        Self { value, mode }
+    }
+
+    pub fn unwrap(self) -> T {
+        self.value
+    }
+
+    /// Fallible add: dispatches on `self.mode.overflow` (see `OverflowMode`).
+    pub fn try_add(self, rhs: Self) -> Result<Self, MazieError> {
+        let value = resolve_overflow(
+            self.mode.overflow,
+            self.value.wrapping_add(rhs.value),
+            self.value.saturating_add(rhs.value),
+            self.value.checked_add(rhs.value),
+        )?;
+        Ok(Self::with_mode(value, self.mode))
+    }
+
+    pub fn try_sub(self, rhs: Self) -> Result<Self, MazieError> {
+        let value = resolve_overflow(
+            self.mode.overflow,
+            self.value.wrapping_sub(rhs.value),
+            self.value.saturating_sub(rhs.value),
+            self.value.checked_sub(rhs.value),
+        )?;
+        Ok(Self::with_mode(value, self.mode))
+    }
+
+    pub fn try_mul(self, rhs: Self) -> Result<Self, MazieError> {
+        let value = resolve_overflow(
+            self.mode.overflow,
+            self.value.wrapping_mul(rhs.value),
+            self.value.saturating_mul(rhs.value),
+            self.value.checked_mul(rhs.value),
+        )?;
+        Ok(Self::with_mode(value, self.mode))
+    }
+
+    pub fn try_neg(self) -> Result<Self, MazieError> {
+        Ok(Self::with_mode(-self.value, self.mode))
+    }
+
+    /// Fallible division: `Div0Policy::Panic` returns `Err` instead of
+    /// unwinding, so library callers in strict mode can handle x/0
+    /// gracefully.
+    pub fn try_div(self, rhs: Self) -> Result<Self, MazieError> {
+        if rhs.value == T::zero() {
+            if let Div0Policy::Panic = self.mode.div0 {
+                return Err(MazieError::DivByZero);
+            }
+            let value = resolve_div0(self.mode.div0, self.value)?;
+            return Ok(Self::with_mode(value, self.mode));
+        }
+        Ok(Self::with_mode(self.value / rhs.value, self.mode))
+    }
+}
+
+/// Errors surfaced by the `try_*` arithmetic API.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum MazieError {
+    DivByZero,
+    Overflow,
+    /// The chosen `Div0Policy` has no meaning for `T` (e.g. `Infinity`/`Nan`
+    /// picked for an integer type, which has no such value).
+    UnsupportedPolicy,
+}
+
+/// The original `f64`-flavored MazieNum, kept as a type alias so existing
+/// call sites (and the demo in `examples/mazie_runtime_demo.rs`) don't need
+/// to change.
+pub type MazieNum = MazieNumT<f64>;
+
+// Convenience constructor
+pub fn m(x: f64) -> MazieNum {
+    MazieNum::new(x)
+}
+
+pub fn m_mode(x: f64, mode: MazieMode<f64>) -> MazieNum {
+    MazieNum::with_mode(x, mode)
+}
+
+// Display as a plain number for easy printing
+impl<T: MazieNumeric + fmt::Display> fmt::Display for MazieNumT<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.value)
+    }
+}
+
+/// Apply a `Div0Policy` to a division whose divisor was found to be zero.
+/// Returns `Err(MazieError::UnsupportedPolicy)` rather than panicking when
+/// `Infinity`/`Nan` is picked for a `T` with no such value, so those two
+/// policies never make `try_div` unwind. `Fallthrough` still panics
+/// natively for integer `T`.
+fn resolve_div0<T: MazieNumeric>(policy: Div0Policy<T>, numerator: T) -> Result<T, MazieError> {
+    match policy {
+        Div0Policy::Identity => Ok(numerator),
+        Div0Policy::Panic => panic!("division by zero (Div0Policy::Panic)"),
+        Div0Policy::Value(v) => Ok(v),
+        Div0Policy::Infinity => {
+            if numerator > T::zero() {
+                T::infinity().ok_or(MazieError::UnsupportedPolicy)
+            } else if numerator < T::zero() {
+                T::infinity().map(Neg::neg).ok_or(MazieError::UnsupportedPolicy)
+            } else {
+                Ok(T::zero())
+            }
+        }
+        Div0Policy::Nan => T::nan().ok_or(MazieError::UnsupportedPolicy),
+        Div0Policy::Fallthrough => Ok(numerator / T::zero()),
+    }
+}
+
+/// Apply an `OverflowMode` to an add/sub/mul, given that operation's
+/// wrapping, saturating, and checked variants.
+fn resolve_overflow<T: MazieNumeric>(
+    mode: OverflowMode,
+    wrapping: T,
+    saturating: T,
+    checked: Option<T>,
+) -> Result<T, MazieError> {
+    match mode {
+        OverflowMode::Wrapping => Ok(wrapping),
+        OverflowMode::Saturating => Ok(saturating),
+        OverflowMode::Checked => checked.ok_or(MazieError::Overflow),
+        OverflowMode::Panicking => {
+            Ok(checked.expect("MazieNumT arithmetic overflowed (OverflowMode::Panicking)"))
+        }
+    }
+}
+
+// --- Operators (MazieNumT<T> op MazieNumT<T>) ---
+// Thin panicking wrappers over the `try_*` API above, so behavior is
+// unchanged for existing callers.
+impl<T: MazieNumeric> Add for MazieNumT<T> {
+    type Output = MazieNumT<T>;
+    fn add(self, rhs: MazieNumT<T>) -> MazieNumT<T> {
+        self.try_add(rhs).expect("MazieNumT add overflowed")
+    }
+}
+
+impl<T: MazieNumeric> Sub for MazieNumT<T> {
+    type Output = MazieNumT<T>;
+    fn sub(self, rhs: MazieNumT<T>) -> MazieNumT<T> {
+        self.try_sub(rhs).expect("MazieNumT sub overflowed")
+    }
+}
+
+impl<T: MazieNumeric> Mul for MazieNumT<T> {
+    type Output = MazieNumT<T>;
+    fn mul(self, rhs: MazieNumT<T>) -> MazieNumT<T> {
+        self.try_mul(rhs).expect("MazieNumT mul overflowed")
+    }
+}
+
+impl<T: MazieNumeric> Div for MazieNumT<T> {
+    type Output = MazieNumT<T>;
+    fn div(self, rhs: MazieNumT<T>) -> MazieNumT<T> {
+        self.try_div(rhs).expect("division by zero (Div0Policy::Panic)")
+    }
+}
+
+impl<T: MazieNumeric> Neg for MazieNumT<T> {
+    type Output = MazieNumT<T>;
+    fn neg(self) -> MazieNumT<T> {
+        self.try_neg().expect("MazieNumT neg overflowed")
+    }
+}
+
+// --- Optional: allow MazieNumT<T> op T (ergonomics) ---
+impl<T: MazieNumeric> Add<T> for MazieNumT<T> {
+    type Output = MazieNumT<T>;
+    fn add(self, rhs: T) -> MazieNumT<T> {
+        self.try_add(MazieNumT::with_mode(rhs, self.mode)).expect("MazieNumT add overflowed")
+    }
+}
+
+impl<T: MazieNumeric> Sub<T> for MazieNumT<T> {
+    type Output = MazieNumT<T>;
+    fn sub(self, rhs: T) -> MazieNumT<T> {
+        self.try_sub(MazieNumT::with_mode(rhs, self.mode)).expect("MazieNumT sub overflowed")
+    }
+}
+
+impl<T: MazieNumeric> Mul<T> for MazieNumT<T> {
+    type Output = MazieNumT<T>;
+    fn mul(self, rhs: T) -> MazieNumT<T> {
+        self.try_mul(MazieNumT::with_mode(rhs, self.mode)).expect("MazieNumT mul overflowed")
+    }
+}
+
+impl<T: MazieNumeric> Div<T> for MazieNumT<T> {
+    type Output = MazieNumT<T>;
+    fn div(self, rhs: T) -> MazieNumT<T> {
+        self.try_div(MazieNumT::with_mode(rhs, self.mode)).expect("division by zero (Div0Policy::Panic)")
+    }
+}
+
+// --- num-traits-style Zero/One/Num so MazieNumT<T> plugs into generic code ---
+
+/// Mirrors `num_traits::Zero`. `is_zero` and the `Div` impl's zero check
+/// agree by construction: both compare `value` against `T::zero()`, so a
+/// generic caller that checks `is_zero()` before dividing sees exactly the
+/// same zero/non-zero classification `Div` itself uses to pick a `Div0Policy`
+/// branch.
+pub trait Zero: Sized {
+    fn zero() -> Self;
+    fn is_zero(&self) -> bool;
+}
+
+impl<T: MazieNumeric> Zero for MazieNumT<T> {
+    fn zero() -> Self {
+        MazieNumT::new(T::zero())
+    }
+
+    fn is_zero(&self) -> bool {
+        self.value == T::zero()
+    }
+}
+
+/// Mirrors `num_traits::One`.
+pub trait One: Sized {
+    fn one() -> Self;
+}
+
+impl<T: MazieNumeric> One for MazieNumT<T> {
+    fn one() -> Self {
+        MazieNumT::new(T::one())
+    }
+}
+
+/// Mirrors `num_traits::Num`: ties the operator impls together with a
+/// parser, so generic algorithms written against `T: Num` accept MazieNumT.
+pub trait Num: Zero + One + PartialEq + Add<Output = Self> + Sub<Output = Self> + Mul<Output = Self> + Div<Output = Self> {
+    type FromStrRadixErr;
+    fn from_str_radix(s: &str, radix: u32) -> Result<Self, Self::FromStrRadixErr>;
+}
+
+impl<T: MazieNumeric> Num for MazieNumT<T> {
+    type FromStrRadixErr = MazieParseError;
+
+    fn from_str_radix(s: &str, radix: u32) -> Result<Self, MazieParseError> {
+        T::from_str_radix(s, radix).map(MazieNumT::new)
+    }
+}
+
+// --- Modular arithmetic mode ---
+// This lives outside `MazieNumT<T>`/`MazieNumeric` because modular negation
+// (`p - a`) needs the modulus itself, and `MazieNumeric`'s `Neg` bound has
+// no way to hand that to a `u64` impl (unlike `f64`/`i32`/`i64`, `u64` has
+// no native `Neg` at all). So `ModularNum` carries its modulus alongside
+// its value and implements the operators directly.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct ModularNum {
+    value: u64,
+    modulus: u64,
+    div0: Div0Policy<u64>,
+}
+
+/// Construct a canonical residue of `x` mod `p` under the identity div0
+/// policy (matches `m`'s default for `MazieNum`).
+pub fn modular(x: u64, p: u64) -> ModularNum {
+    ModularNum { value: x % p, modulus: p, div0: Div0Policy::Identity }
+}
+
+fn mod_inverse(b: u64, p: u64) -> u64 {
+    // Fermat's little theorem: b^(p-2) mod p, valid when p is prime.
+    let mut base = b % p;
+    let mut exp = p - 2;
+    let mut result = 1u64;
+    while exp > 0 {
+        if exp & 1 == 1 {
+            result = (result as u128 * base as u128 % p as u128) as u64;
+        }
+        base = (base as u128 * base as u128 % p as u128) as u64;
+        exp >>= 1;
+    }
+    result
+}
+
+fn resolve_div0_modular(policy: Div0Policy<u64>, numerator: u64) -> u64 {
+    match policy {
+        Div0Policy::Identity => numerator,
+        Div0Policy::Panic => panic!("division by zero (Div0Policy::Panic)"),
+        Div0Policy::Value(v) => v,
+        Div0Policy::Infinity | Div0Policy::Nan | Div0Policy::Fallthrough => {
+            panic!("this Div0Policy has no meaning for modular arithmetic")
+        }
+    }
+}
+
+impl ModularNum {
+    pub fn unwrap(self) -> u64 {
+        self.value
+    }
+
+    pub fn modulus(self) -> u64 {
+        self.modulus
+    }
+
+    /// Change the modulus in place, re-reducing the current value by the
+    /// new modulus. Only the residue is kept, not the value it was built
+    /// from: `modular(10, 7)` then `set_modulus(4)` holds 3 (3 mod 4), not
+    /// 2 (10 mod 4).
+    pub fn set_modulus(&mut self, p: u64) {
+        self.modulus = p;
+        self.value %= p;
+    }
+
+    pub fn try_add(self, rhs: Self) -> Result<Self, MazieError> {
+        let s = self.value + rhs.value;
+        let value = if s >= self.modulus { s - self.modulus } else { s };
+        Ok(ModularNum { value, ..self })
+    }
+
+    pub fn try_sub(self, rhs: Self) -> Result<Self, MazieError> {
+        self.try_add(ModularNum { value: self.neg_value(rhs.value), ..self })
+    }
+
+    pub fn try_mul(self, rhs: Self) -> Result<Self, MazieError> {
+        let value = (self.value as u128 * rhs.value as u128 % self.modulus as u128) as u64;
+        Ok(ModularNum { value, ..self })
+    }
+
+    pub fn try_neg(self) -> Result<Self, MazieError> {
+        Ok(ModularNum { value: self.neg_value(self.value), ..self })
+    }
+
+    fn neg_value(self, a: u64) -> u64 {
+        if a == 0 {
+            0
+        } else {
+            self.modulus - a
+        }
+    }
+
+    /// `a / b` multiplies `a` by `b`'s modular inverse (Fermat's little
+    /// theorem), falling back to `self.div0` when `b` is congruent to 0.
+    pub fn try_div(self, rhs: Self) -> Result<Self, MazieError> {
+        if rhs.value == 0 {
+            if let Div0Policy::Panic = self.div0 {
+                return Err(MazieError::DivByZero);
+            }
+            return Ok(ModularNum { value: resolve_div0_modular(self.div0, self.value), ..self });
+        }
+        let value = (self.value as u128 * mod_inverse(rhs.value, self.modulus) as u128
+            % self.modulus as u128) as u64;
+        Ok(ModularNum { value, ..self })
+    }
+}
+
+impl Add for ModularNum {
+    type Output = ModularNum;
+    fn add(self, rhs: ModularNum) -> ModularNum {
+        self.try_add(rhs).expect("ModularNum add overflowed")
+    }
+}
+
+impl Sub for ModularNum {
+    type Output = ModularNum;
+    fn sub(self, rhs: ModularNum) -> ModularNum {
+        self.try_sub(rhs).expect("ModularNum sub overflowed")
+    }
+}
+
+impl Mul for ModularNum {
+    type Output = ModularNum;
+    fn mul(self, rhs: ModularNum) -> ModularNum {
+        self.try_mul(rhs).expect("ModularNum mul overflowed")
+    }
+}
+
+impl Div for ModularNum {
+    type Output = ModularNum;
+    fn div(self, rhs: ModularNum) -> ModularNum {
+        self.try_div(rhs).expect("division by zero (Div0Policy::Panic)")
+    }
+}
+
+impl Neg for ModularNum {
+    type Output = ModularNum;
+    fn neg(self) -> ModularNum {
+        self.try_neg().expect("ModularNum neg overflowed")
+    }
+}
+
+impl fmt::Display for ModularNum {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // --- Div0Policy ---
+
+    #[test]
+    fn div0_identity_default_f64() {
+        let x = m(5.0);
+        assert_eq!((x / 0.0).unwrap(), 5.0);
+    }
+
+    #[test]
+    fn div0_identity_default_i32() {
+        let x = MazieNumT::<i32>::new(5);
+        assert_eq!((x / 0).unwrap(), 5);
+    }
+
+    #[test]
+    fn div0_panic_returns_err_instead_of_unwinding() {
+        let mode = MazieMode { div0: Div0Policy::Panic, overflow: OverflowMode::default() };
+        let x = MazieNumT::with_mode(5.0, mode);
+        assert_eq!(x.try_div(MazieNumT::with_mode(0.0, mode)), Err(MazieError::DivByZero));
+    }
+
+    #[test]
+    #[should_panic(expected = "Div0Policy::Panic")]
+    fn div0_panic_operator_panics() {
+        let mode = MazieMode { div0: Div0Policy::Panic, overflow: OverflowMode::default() };
+        let x = MazieNumT::with_mode(5.0, mode);
+        let _ = x / MazieNumT::with_mode(0.0, mode);
+    }
+
+    #[test]
+    fn div0_value_returns_fixed_constant() {
+        let mode = MazieMode { div0: Div0Policy::Value(42.0), overflow: OverflowMode::default() };
+        let x = MazieNumT::with_mode(5.0, mode);
+        assert_eq!((x / 0.0).unwrap(), 42.0);
+    }
+
+    #[test]
+    fn div0_infinity_follows_sign_of_numerator_f64() {
+        let mode = MazieMode { div0: Div0Policy::Infinity, overflow: OverflowMode::default() };
+        assert_eq!((MazieNumT::with_mode(5.0, mode) / 0.0).unwrap(), f64::INFINITY);
+        assert_eq!((MazieNumT::with_mode(-5.0, mode) / 0.0).unwrap(), f64::NEG_INFINITY);
+        assert_eq!((MazieNumT::with_mode(0.0, mode) / 0.0).unwrap(), 0.0);
+    }
+
+    #[test]
+    fn div0_infinity_unsupported_for_i32() {
+        let mode = MazieMode { div0: Div0Policy::Infinity, overflow: OverflowMode::default() };
+        let x = MazieNumT::with_mode(5, mode);
+        assert_eq!(x.try_div(MazieNumT::with_mode(0, mode)), Err(MazieError::UnsupportedPolicy));
+    }
+
+    #[test]
+    fn div0_nan_f64() {
+        let mode = MazieMode { div0: Div0Policy::Nan, overflow: OverflowMode::default() };
+        let x = MazieNumT::with_mode(5.0, mode);
+        assert!((x / 0.0).unwrap().is_nan());
+    }
+
+    #[test]
+    fn div0_nan_unsupported_for_i64() {
+        let mode = MazieMode { div0: Div0Policy::Nan, overflow: OverflowMode::default() };
+        let x = MazieNumT::with_mode(5i64, mode);
+        assert_eq!(x.try_div(MazieNumT::with_mode(0, mode)), Err(MazieError::UnsupportedPolicy));
+    }
+
+    #[test]
+    fn div0_fallthrough_f64_matches_ieee() {
+        let mode = MazieMode { div0: Div0Policy::Fallthrough, overflow: OverflowMode::default() };
+        assert_eq!((MazieNumT::with_mode(5.0, mode) / 0.0).unwrap(), f64::INFINITY);
+    }
+
+    #[test]
+    #[should_panic]
+    fn div0_fallthrough_i32_panics_natively() {
+        let mode = MazieMode { div0: Div0Policy::Fallthrough, overflow: OverflowMode::default() };
+        let x = MazieNumT::with_mode(5, mode);
+        let _ = x.try_div(MazieNumT::with_mode(0, mode));
+    }
+
+    // --- OverflowMode ---
+
+    #[test]
+    fn overflow_wrapping_i32() {
+        let mode = MazieMode { div0: Div0Policy::default(), overflow: OverflowMode::Wrapping };
+        let x = MazieNumT::with_mode(i32::MAX, mode);
+        let y = MazieNumT::with_mode(1, mode);
+        assert_eq!((x + y).unwrap(), i32::MIN);
+    }
+
+    #[test]
+    fn overflow_saturating_i64() {
+        let mode = MazieMode { div0: Div0Policy::default(), overflow: OverflowMode::Saturating };
+        let x = MazieNumT::with_mode(i64::MAX, mode);
+        let y = MazieNumT::with_mode(1, mode);
+        assert_eq!((x + y).unwrap(), i64::MAX);
+    }
+
+    #[test]
+    fn overflow_checked_returns_err() {
+        let mode = MazieMode { div0: Div0Policy::default(), overflow: OverflowMode::Checked };
+        let x = MazieNumT::with_mode(i32::MAX, mode);
+        let y = MazieNumT::with_mode(1, mode);
+        assert_eq!(x.try_add(y), Err(MazieError::Overflow));
+    }
+
+    #[test]
+    #[should_panic(expected = "overflowed")]
+    fn overflow_panicking_default_panics() {
+        let x = MazieNumT::<i32>::new(i32::MAX);
+        let _ = x + MazieNumT::new(1);
+    }
+
+    #[test]
+    fn overflow_modes_are_noops_for_f64() {
+        for overflow in [
+            OverflowMode::Wrapping,
+            OverflowMode::Saturating,
+            OverflowMode::Checked,
+            OverflowMode::Panicking,
+        ] {
+            let mode = MazieMode { div0: Div0Policy::default(), overflow };
+            let x = MazieNumT::with_mode(1.5, mode);
+            let y = MazieNumT::with_mode(2.5, mode);
+            assert_eq!((x + y).unwrap(), 4.0);
+        }
+    }
+
+    // --- Modular arithmetic ---
+
+    #[test]
+    fn modular_reduces_into_canonical_range() {
+        assert_eq!(modular(10, 7).unwrap(), 3);
+    }
+
+    #[test]
+    fn modular_div_is_inverse_of_mul() {
+        // 1 / 3 mod 7 == 5, since 3 * 5 = 15 = 2*7 + 1
+        let one = modular(1, 7);
+        let three = modular(3, 7);
+        assert_eq!((one / three).unwrap(), 5);
+    }
+
+    #[test]
+    fn modular_div_then_mul_recovers_numerator() {
+        let a = modular(4, 13);
+        let b = modular(6, 13);
+        assert_eq!(((a / b) * b).unwrap(), a.unwrap());
+    }
+
+    #[test]
+    fn modular_set_modulus_re_reduces_value() {
+        let mut x = modular(10, 7);
+        assert_eq!(x.unwrap(), 3);
+        x.set_modulus(4);
+        assert_eq!(x.unwrap(), 3);
+        assert_eq!(x.modulus(), 4);
+    }
+
+    #[test]
+    fn modular_div0_identity_default() {
+        let x = modular(5, 7);
+        let zero = modular(0, 7);
+        assert_eq!((x / zero).unwrap(), 5);
+    }
+}
@@ -0,0 +1,604 @@
+//! Mazie "runtime mode" context: the semantics live in MazieRuntime,
+//! not scattered across operator overloads.
+//!
+//! `no_std` by default with `std` enabled via the `std` Cargo feature (on by
+//! default). Float-only helpers that need libm-style math (sqrt, abs, recip,
+//! ...) should be gated behind the `libm` feature going forward, routing to
+//! the `libm` crate when `std` isn't available. The demo that used to live
+//! in this file's `main()` moved to `examples/mazie_runtime_mode_demo.rs`,
+//! since examples always have `std`.
+
+use core::fmt;
+use core::marker::PhantomData;
+use core::ops::Neg;
+
+use crate::numeric::{MazieNumeric, MazieParseError};
+
+/// Division-by-zero policy. `Infinity`/`Nan`/`Fallthrough` lean on IEEE
+/// float semantics. For a non-float `T`, `Infinity`/`Nan` make `try_div`
+/// return `Err(MazieError::UnsupportedPolicy)` (`MazieNumeric::infinity`/
+/// `::nan` give `None`), and `Fallthrough` panics natively.
+#[derive(Copy, Clone, Debug, PartialEq, Default)]
+pub enum Div0Policy<T> {
+    /// x / 0 => x
+    #[default]
+    Identity,
+    /// x / 0 => panic
+    Panic,
+    /// x / 0 => a fixed constant
+    Value(T),
+    /// x / 0 => IEEE +/-infinity, following the sign of the numerator
+    /// (a zero numerator gives zero)
+    Infinity,
+    /// x / 0 => IEEE NaN
+    Nan,
+    /// x / 0 => perform the raw division, letting T decide (inf/nan for
+    /// floats, a native panic for integers)
+    Fallthrough,
+}
+
+/// Apply a `Div0Policy` to a division whose divisor was found to be zero.
+/// Returns `Err(MazieError::UnsupportedPolicy)` rather than panicking when
+/// `Infinity`/`Nan` is picked for a `T` with no such value, so those two
+/// policies never make `try_div` unwind. `Fallthrough` still panics
+/// natively for integer `T`.
+fn resolve_div0<T: MazieNumeric>(policy: Div0Policy<T>, numerator: T) -> Result<T, MazieError> {
+    match policy {
+        Div0Policy::Identity => Ok(numerator),
+        Div0Policy::Panic => panic!("division by zero (Div0Policy::Panic)"),
+        Div0Policy::Value(v) => Ok(v),
+        Div0Policy::Infinity => {
+            if numerator > T::zero() {
+                T::infinity().ok_or(MazieError::UnsupportedPolicy)
+            } else if numerator < T::zero() {
+                T::infinity().map(Neg::neg).ok_or(MazieError::UnsupportedPolicy)
+            } else {
+                Ok(T::zero())
+            }
+        }
+        Div0Policy::Nan => T::nan().ok_or(MazieError::UnsupportedPolicy),
+        Div0Policy::Fallthrough => Ok(numerator / T::zero()),
+    }
+}
+
+/// Integer-overflow policy for `add`/`sub`/`mul`, mirroring Rust's own
+/// `wrapping_*`/`saturating_*`/`checked_*` integer ops. For `f64` these all
+/// collapse to ordinary IEEE arithmetic (see `MazieNumeric`'s impl for f64).
+#[derive(Copy, Clone, Debug, PartialEq, Default)]
+pub enum OverflowMode {
+    /// Wrap around on overflow (two's-complement wraparound for integers).
+    Wrapping,
+    /// Clamp to the type's min/max on overflow.
+    Saturating,
+    /// Treat overflow as a `MazieError::Overflow`.
+    Checked,
+    /// Overflow panics (the default, matching Rust's debug-mode integer ops).
+    #[default]
+    Panicking,
+}
+
+/// Combine the three precomputed results of an overflow-sensitive op
+/// (wrapping, saturating, checked) per the chosen `OverflowMode`.
+fn resolve_overflow<T: MazieNumeric>(
+    mode: OverflowMode,
+    wrapping: T,
+    saturating: T,
+    checked: Option<T>,
+) -> Result<T, MazieError> {
+    match mode {
+        OverflowMode::Wrapping => Ok(wrapping),
+        OverflowMode::Saturating => Ok(saturating),
+        OverflowMode::Checked => checked.ok_or(MazieError::Overflow),
+        OverflowMode::Panicking => {
+            Ok(checked.expect("MazieRuntime arithmetic overflowed (OverflowMode::Panicking)"))
+        }
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct MazieMode<T: MazieNumeric> {
+    pub div0: Div0Policy<T>,
+    pub overflow: OverflowMode,
+}
+
+impl<T: MazieNumeric> Default for MazieMode<T> {
+    fn default() -> Self {
+        Self { div0: Div0Policy::default(), overflow: OverflowMode::default() }
+    }
+}
+
+/// A number tagged with a specific MazieRuntime instance via its mode copy.
+/// (In a larger system you could store an Arc<MazieRuntime> instead,
+/// but this stays lightweight.)
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct MazieNumT<T: MazieNumeric> {
+    value: T,
+    mode: MazieMode<T>,
+}
+
+impl<T: MazieNumeric> MazieNumT<T> {
+    pub fn unwrap(self) -> T {
+        self.value
+    }
+}
+
+/// The original `f64`-flavored MazieNum, kept as a type alias so existing
+/// call sites (and the demo in `examples/mazie_runtime_mode_demo.rs`) don't
+/// need to change.
+pub type MazieNum = MazieNumT<f64>;
+
+impl<T: MazieNumeric + fmt::Display> fmt::Display for MazieNumT<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.value)
+    }
+}
+
+// --- num-traits-style Zero/One/Num so MazieNumT<T> plugs into generic code ---
+// These are unbound to any particular MazieRuntime (no `self` is available
+// to an associated function), so they construct under the default mode;
+// callers can `runtime.bind(...)` the result back into their runtime.
+
+/// Mirrors `num_traits::Zero`. `is_zero` and `MazieRuntime::div`'s zero
+/// check agree by construction: both compare `value` against `T::zero()`,
+/// so a generic caller that checks `is_zero()` before dividing sees exactly
+/// the same zero/non-zero classification `MazieRuntime::div` itself uses to
+/// pick a `Div0Policy` branch.
+pub trait Zero: Sized {
+    fn zero() -> Self;
+    fn is_zero(&self) -> bool;
+}
+
+impl<T: MazieNumeric> Zero for MazieNumT<T> {
+    fn zero() -> Self {
+        MazieNumT { value: T::zero(), mode: MazieMode::default() }
+    }
+
+    fn is_zero(&self) -> bool {
+        self.value == T::zero()
+    }
+}
+
+/// Mirrors `num_traits::One`.
+pub trait One: Sized {
+    fn one() -> Self;
+}
+
+impl<T: MazieNumeric> One for MazieNumT<T> {
+    fn one() -> Self {
+        MazieNumT { value: T::one(), mode: MazieMode::default() }
+    }
+}
+
+// Note: this file deliberately has no Add/Sub/Mul/Div impls on MazieNumT
+// itself (arithmetic is routed through MazieRuntime so the semantics live
+// in one place), so the full num-traits `Num` trait doesn't fit here the
+// way it does in mazie_runtime.rs. `from_str_radix` is exposed as a plain
+// associated function instead.
+impl<T: MazieNumeric> MazieNumT<T> {
+    pub fn from_str_radix(s: &str, radix: u32) -> Result<Self, MazieParseError> {
+        T::from_str_radix(s, radix).map(|value| MazieNumT { value, mode: MazieMode::default() })
+    }
+}
+
+/// Errors surfaced by the `try_*` arithmetic API.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum MazieError {
+    DivByZero,
+    Overflow,
+    /// The chosen `Div0Policy` has no meaning for `T` (e.g. `Infinity`/`Nan`
+    /// picked for an integer type, which has no such value).
+    UnsupportedPolicy,
+}
+
+/// The "runtime mode" abstraction.
+/// This is the API you use to perform arithmetic under chosen semantics.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct MazieRuntime<T: MazieNumeric> {
+    pub mode: MazieMode<T>,
+    pub name: &'static str,
+    _numeric: PhantomData<T>,
+}
+
+impl<T: MazieNumeric> MazieRuntime<T> {
+    /// Construct a MazieNumT<T> bound to this runtime's mode.
+    pub fn n(&self, x: T) -> MazieNumT<T> {
+        MazieNumT { value: x, mode: self.mode }
+    }
+
+    /// Ensure the number is under this runtime (rebind semantics if needed).
+    pub fn bind(&self, x: MazieNumT<T>) -> MazieNumT<T> {
+        MazieNumT { value: x.value, mode: self.mode }
+    }
+
+    // ---- Runtime arithmetic functions ----
+    // `add`/`sub`/`mul`/`div`/`neg` are thin panicking wrappers over the
+    // `try_*` API below, so behavior is unchanged for existing callers.
+
+    /// Fallible add: dispatches on `self.mode.overflow` (see `OverflowMode`).
+    pub fn try_add(&self, a: MazieNumT<T>, b: MazieNumT<T>) -> Result<MazieNumT<T>, MazieError> {
+        let a = self.bind(a);
+        let b = self.bind(b);
+        let value = resolve_overflow(
+            self.mode.overflow,
+            a.value.wrapping_add(b.value),
+            a.value.saturating_add(b.value),
+            a.value.checked_add(b.value),
+        )?;
+        Ok(MazieNumT { value, mode: self.mode })
+    }
+
+    pub fn try_sub(&self, a: MazieNumT<T>, b: MazieNumT<T>) -> Result<MazieNumT<T>, MazieError> {
+        let a = self.bind(a);
+        let b = self.bind(b);
+        let value = resolve_overflow(
+            self.mode.overflow,
+            a.value.wrapping_sub(b.value),
+            a.value.saturating_sub(b.value),
+            a.value.checked_sub(b.value),
+        )?;
+        Ok(MazieNumT { value, mode: self.mode })
+    }
+
+    pub fn try_mul(&self, a: MazieNumT<T>, b: MazieNumT<T>) -> Result<MazieNumT<T>, MazieError> {
+        let a = self.bind(a);
+        let b = self.bind(b);
+        let value = resolve_overflow(
+            self.mode.overflow,
+            a.value.wrapping_mul(b.value),
+            a.value.saturating_mul(b.value),
+            a.value.checked_mul(b.value),
+        )?;
+        Ok(MazieNumT { value, mode: self.mode })
+    }
+
+    pub fn try_neg(&self, a: MazieNumT<T>) -> Result<MazieNumT<T>, MazieError> {
+        let a = self.bind(a);
+        Ok(MazieNumT { value: -a.value, mode: self.mode })
+    }
+
+    /// Fallible division under runtime semantics: `Div0Policy::Panic`
+    /// returns `Err` instead of unwinding, so strict-mode callers can
+    /// handle x/0 gracefully.
+    pub fn try_div(&self, a: MazieNumT<T>, b: MazieNumT<T>) -> Result<MazieNumT<T>, MazieError> {
+        let a = self.bind(a);
+        let b = self.bind(b);
+
+        if b.value == T::zero() {
+            if let Div0Policy::Panic = self.mode.div0 {
+                return Err(MazieError::DivByZero);
+            }
+            let value = resolve_div0(self.mode.div0, a.value)?;
+            return Ok(MazieNumT { value, mode: self.mode });
+        }
+
+        Ok(MazieNumT { value: a.value / b.value, mode: self.mode })
+    }
+
+    pub fn add(&self, a: MazieNumT<T>, b: MazieNumT<T>) -> MazieNumT<T> {
+        self.try_add(a, b).expect("MazieRuntime add overflowed")
+    }
+
+    pub fn sub(&self, a: MazieNumT<T>, b: MazieNumT<T>) -> MazieNumT<T> {
+        self.try_sub(a, b).expect("MazieRuntime sub overflowed")
+    }
+
+    pub fn mul(&self, a: MazieNumT<T>, b: MazieNumT<T>) -> MazieNumT<T> {
+        self.try_mul(a, b).expect("MazieRuntime mul overflowed")
+    }
+
+    pub fn neg(&self, a: MazieNumT<T>) -> MazieNumT<T> {
+        self.try_neg(a).expect("MazieRuntime neg overflowed")
+    }
+
+    /// Division under runtime semantics: dispatches on `self.mode.div0`
+    /// (see `Div0Policy`) when the divisor is zero.
+    pub fn div(&self, a: MazieNumT<T>, b: MazieNumT<T>) -> MazieNumT<T> {
+        self.try_div(a, b).expect("division by zero (div0=Div0Policy::Panic)")
+    }
+
+    /// Convenience overloads (so you can pass a raw T too)
+    pub fn addf(&self, a: MazieNumT<T>, b: T) -> MazieNumT<T> { self.add(a, self.n(b)) }
+    pub fn subf(&self, a: MazieNumT<T>, b: T) -> MazieNumT<T> { self.sub(a, self.n(b)) }
+    pub fn mulf(&self, a: MazieNumT<T>, b: T) -> MazieNumT<T> { self.mul(a, self.n(b)) }
+    pub fn divf(&self, a: MazieNumT<T>, b: T) -> MazieNumT<T> { self.div(a, self.n(b)) }
+
+    /// Default Mazie runtime: identity-preserving division by zero enabled.
+    pub fn mazie() -> Self {
+        Self {
+            mode: MazieMode { div0: Div0Policy::Identity, overflow: OverflowMode::default() },
+            name: "MazieRuntime::mazie",
+            _numeric: PhantomData,
+        }
+    }
+
+    /// Strict runtime: division by zero is a hard error (panic).
+    pub fn strict() -> Self {
+        Self {
+            mode: MazieMode { div0: Div0Policy::Panic, overflow: OverflowMode::default() },
+            name: "MazieRuntime::strict",
+            _numeric: PhantomData,
+        }
+    }
+
+    /// IEEE runtime: division by zero falls through to T's native
+    /// semantics (inf/nan for floats, a panic for integers).
+    pub fn ieee() -> Self {
+        Self {
+            mode: MazieMode { div0: Div0Policy::Fallthrough, overflow: OverflowMode::default() },
+            name: "MazieRuntime::ieee",
+            _numeric: PhantomData,
+        }
+    }
+
+    /// Saturating runtime: division by zero returns a fixed constant.
+    pub fn saturating(value: T) -> Self {
+        Self {
+            mode: MazieMode { div0: Div0Policy::Value(value), overflow: OverflowMode::default() },
+            name: "MazieRuntime::saturating",
+            _numeric: PhantomData,
+        }
+    }
+}
+
+fn resolve_div0_modular(policy: Div0Policy<u64>, numerator: u64) -> u64 {
+    match policy {
+        Div0Policy::Identity => numerator,
+        Div0Policy::Panic => panic!("division by zero (Div0Policy::Panic)"),
+        Div0Policy::Value(v) => v,
+        Div0Policy::Infinity | Div0Policy::Nan | Div0Policy::Fallthrough => {
+            panic!("this Div0Policy has no meaning for modular arithmetic")
+        }
+    }
+}
+
+/// A modular-arithmetic runtime: all values are canonical residues in
+/// `[0, p)`. This is a dedicated sibling of `MazieRuntime<T>` rather than an
+/// instantiation of it — modular negation (`p - a`) needs the modulus
+/// itself, and `MazieNumeric`'s `Neg` bound has no way to hand that to a
+/// `u64` impl (`u64` has no native `Neg` at all, unlike `f64`/`i32`/`i64`).
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct ModularRuntime {
+    pub modulus: u64,
+    pub div0: Div0Policy<u64>,
+    pub name: &'static str,
+}
+
+impl ModularRuntime {
+    /// A modular-arithmetic runtime under prime `p`. Division multiplies by
+    /// the modular inverse of the divisor (Fermat's little theorem), which
+    /// requires `p` to be prime.
+    pub fn modular(p: u64) -> Self {
+        Self { modulus: p, div0: Div0Policy::Identity, name: "MazieRuntime::modular" }
+    }
+
+    /// Change the modulus on this runtime, re-reducing is left to the
+    /// caller via `n`, so the same data can be recomputed under several
+    /// primes.
+    pub fn set_modulus(&mut self, p: u64) {
+        self.modulus = p;
+    }
+
+    /// Reduce a raw integer into this runtime's canonical residue range.
+    pub fn n(&self, x: u64) -> u64 {
+        x % self.modulus
+    }
+
+    pub fn add(&self, a: u64, b: u64) -> u64 {
+        let s = a + b;
+        if s >= self.modulus {
+            s - self.modulus
+        } else {
+            s
+        }
+    }
+
+    pub fn sub(&self, a: u64, b: u64) -> u64 {
+        self.add(a, self.neg(b))
+    }
+
+    pub fn mul(&self, a: u64, b: u64) -> u64 {
+        (a as u128 * b as u128 % self.modulus as u128) as u64
+    }
+
+    pub fn neg(&self, a: u64) -> u64 {
+        if a == 0 {
+            0
+        } else {
+            self.modulus - a
+        }
+    }
+
+    fn inverse(&self, b: u64) -> u64 {
+        // Fermat's little theorem: b^(p-2) mod p, valid when p is prime.
+        let mut base = b % self.modulus;
+        let mut exp = self.modulus - 2;
+        let mut result = 1u64;
+        while exp > 0 {
+            if exp & 1 == 1 {
+                result = self.mul(result, base);
+            }
+            base = self.mul(base, base);
+            exp >>= 1;
+        }
+        result
+    }
+
+    /// `a / b` multiplies `a` by `b`'s modular inverse, falling back to
+    /// `self.div0` when `b` is congruent to 0 mod `p`.
+    pub fn try_div(&self, a: u64, b: u64) -> Result<u64, MazieError> {
+        let a = self.n(a);
+        let b = self.n(b);
+        if b == 0 {
+            if let Div0Policy::Panic = self.div0 {
+                return Err(MazieError::DivByZero);
+            }
+            return Ok(resolve_div0_modular(self.div0, a));
+        }
+        Ok(self.mul(a, self.inverse(b)))
+    }
+
+    pub fn div(&self, a: u64, b: u64) -> u64 {
+        self.try_div(a, b).expect("division by zero (div0=Div0Policy::Panic)")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // --- Div0Policy ---
+
+    #[test]
+    fn div0_identity_default_f64() {
+        let rt = MazieRuntime::<f64>::mazie();
+        assert_eq!(rt.divf(rt.n(5.0), 0.0).unwrap(), 5.0);
+    }
+
+    #[test]
+    fn div0_identity_default_i32() {
+        let rt = MazieRuntime::<i32>::mazie();
+        assert_eq!(rt.divf(rt.n(5), 0).unwrap(), 5);
+    }
+
+    #[test]
+    fn div0_panic_returns_err_instead_of_unwinding() {
+        let rt = MazieRuntime::<f64>::strict();
+        assert_eq!(rt.try_div(rt.n(5.0), rt.n(0.0)), Err(MazieError::DivByZero));
+    }
+
+    #[test]
+    #[should_panic(expected = "Div0Policy::Panic")]
+    fn div0_panic_operator_panics() {
+        let rt = MazieRuntime::<f64>::strict();
+        rt.divf(rt.n(5.0), 0.0);
+    }
+
+    #[test]
+    fn div0_value_returns_fixed_constant() {
+        let rt = MazieRuntime::<f64>::saturating(42.0);
+        assert_eq!(rt.divf(rt.n(5.0), 0.0).unwrap(), 42.0);
+    }
+
+    #[test]
+    fn div0_infinity_follows_sign_of_numerator_f64() {
+        let mode = MazieMode { div0: Div0Policy::Infinity, overflow: OverflowMode::default() };
+        let rt = MazieRuntime { mode, name: "infinity", _numeric: PhantomData::<f64> };
+        assert_eq!(rt.divf(rt.n(5.0), 0.0).unwrap(), f64::INFINITY);
+        assert_eq!(rt.divf(rt.n(-5.0), 0.0).unwrap(), f64::NEG_INFINITY);
+        assert_eq!(rt.divf(rt.n(0.0), 0.0).unwrap(), 0.0);
+    }
+
+    #[test]
+    fn div0_infinity_unsupported_for_i32() {
+        let mode = MazieMode { div0: Div0Policy::Infinity, overflow: OverflowMode::default() };
+        let rt = MazieRuntime { mode, name: "infinity", _numeric: PhantomData::<i32> };
+        assert_eq!(rt.try_div(rt.n(5), rt.n(0)), Err(MazieError::UnsupportedPolicy));
+    }
+
+    #[test]
+    fn div0_nan_f64() {
+        let mode = MazieMode { div0: Div0Policy::Nan, overflow: OverflowMode::default() };
+        let rt = MazieRuntime { mode, name: "nan", _numeric: PhantomData::<f64> };
+        assert!(rt.divf(rt.n(5.0), 0.0).unwrap().is_nan());
+    }
+
+    #[test]
+    fn div0_nan_unsupported_for_i64() {
+        let mode = MazieMode { div0: Div0Policy::Nan, overflow: OverflowMode::default() };
+        let rt = MazieRuntime { mode, name: "nan", _numeric: PhantomData::<i64> };
+        assert_eq!(rt.try_div(rt.n(5), rt.n(0)), Err(MazieError::UnsupportedPolicy));
+    }
+
+    #[test]
+    fn div0_fallthrough_f64_matches_ieee() {
+        let rt = MazieRuntime::<f64>::ieee();
+        assert_eq!(rt.divf(rt.n(5.0), 0.0).unwrap(), f64::INFINITY);
+    }
+
+    #[test]
+    #[should_panic]
+    fn div0_fallthrough_i32_panics_natively() {
+        let rt = MazieRuntime::<i32>::ieee();
+        let _ = rt.try_div(rt.n(5), rt.n(0));
+    }
+
+    // --- OverflowMode ---
+
+    #[test]
+    fn overflow_wrapping_i32() {
+        let mode = MazieMode { div0: Div0Policy::default(), overflow: OverflowMode::Wrapping };
+        let rt = MazieRuntime { mode, name: "wrapping", _numeric: PhantomData::<i32> };
+        assert_eq!(rt.addf(rt.n(i32::MAX), 1).unwrap(), i32::MIN);
+    }
+
+    #[test]
+    fn overflow_saturating_i64() {
+        let mode = MazieMode { div0: Div0Policy::default(), overflow: OverflowMode::Saturating };
+        let rt = MazieRuntime { mode, name: "saturating", _numeric: PhantomData::<i64> };
+        assert_eq!(rt.addf(rt.n(i64::MAX), 1).unwrap(), i64::MAX);
+    }
+
+    #[test]
+    fn overflow_checked_returns_err() {
+        let mode = MazieMode { div0: Div0Policy::default(), overflow: OverflowMode::Checked };
+        let rt = MazieRuntime { mode, name: "checked", _numeric: PhantomData::<i32> };
+        assert_eq!(rt.try_add(rt.n(i32::MAX), rt.n(1)), Err(MazieError::Overflow));
+    }
+
+    #[test]
+    #[should_panic(expected = "overflowed")]
+    fn overflow_panicking_default_panics() {
+        let rt = MazieRuntime::<i32>::mazie();
+        rt.addf(rt.n(i32::MAX), 1);
+    }
+
+    #[test]
+    fn overflow_modes_are_noops_for_f64() {
+        for overflow in [
+            OverflowMode::Wrapping,
+            OverflowMode::Saturating,
+            OverflowMode::Checked,
+            OverflowMode::Panicking,
+        ] {
+            let mode = MazieMode { div0: Div0Policy::default(), overflow };
+            let rt = MazieRuntime { mode, name: "overflow", _numeric: PhantomData::<f64> };
+            assert_eq!(rt.addf(rt.n(1.5), 2.5).unwrap(), 4.0);
+        }
+    }
+
+    // --- Modular arithmetic ---
+
+    #[test]
+    fn modular_reduces_into_canonical_range() {
+        let rt = ModularRuntime::modular(7);
+        assert_eq!(rt.n(10), 3);
+    }
+
+    #[test]
+    fn modular_div_is_inverse_of_mul() {
+        // 1 / 3 mod 7 == 5, since 3 * 5 = 15 = 2*7 + 1
+        let rt = ModularRuntime::modular(7);
+        assert_eq!(rt.div(1, 3), 5);
+    }
+
+    #[test]
+    fn modular_div_then_mul_recovers_numerator() {
+        let rt = ModularRuntime::modular(13);
+        assert_eq!(rt.mul(rt.div(4, 6), 6), rt.n(4));
+    }
+
+    #[test]
+    fn modular_set_modulus_changes_reduction() {
+        let mut rt = ModularRuntime::modular(7);
+        assert_eq!(rt.n(10), 3);
+        rt.set_modulus(4);
+        assert_eq!(rt.n(10), 2);
+    }
+
+    #[test]
+    fn modular_div0_identity_default() {
+        let rt = ModularRuntime::modular(7);
+        assert_eq!(rt.div(5, 0), 5);
+    }
+}
+
@@ -0,0 +1,237 @@
+//! Numeric trait shared by [`crate::mazie_runtime`] and
+//! [`crate::mazie_runtime_mode`]: both flavors run the same div0/overflow
+//! semantics over the same handful of types, so the trait and its `f64`/
+//! `i32`/`i64` impls live here once instead of being hand-copied per module.
+
+use core::ops::{Add, Div, Mul, Neg, Sub};
+
+/// Error returned by `Num::from_str_radix` when a string doesn't parse
+/// as the underlying numeric type (or uses a radix that type can't support).
+/// Carries no heap-allocated payload so it stays usable without `alloc`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum MazieParseError {
+    UnsupportedRadix(u32),
+    Invalid,
+}
+
+/// Numeric types the Mazie runtimes can drive. Modeled on num-traits'
+/// `Num`: the operator bounds plus `zero()`/`one()` constructors and a
+/// `from_str_radix` parser, with `infinity()`/`nan()` for the IEEE div0
+/// policies and wrapping/saturating/checked hooks for `OverflowMode`.
+pub trait MazieNumeric:
+    Copy
+    + PartialEq
+    + PartialOrd
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Div<Output = Self>
+    + Neg<Output = Self>
+{
+    fn zero() -> Self;
+    fn one() -> Self;
+    fn from_str_radix(s: &str, radix: u32) -> Result<Self, MazieParseError>;
+
+    /// IEEE positive infinity, or `None` for types with no such value
+    /// (only meaningful under `Div0Policy::Infinity`).
+    fn infinity() -> Option<Self>;
+
+    /// IEEE NaN, or `None` for types with no such value (only meaningful
+    /// under `Div0Policy::Nan`).
+    fn nan() -> Option<Self>;
+
+    // ---- OverflowMode hooks. For `f64` these all collapse to ordinary
+    // IEEE arithmetic, since floats have no overflow to wrap/saturate/check.
+    fn wrapping_add(self, rhs: Self) -> Self;
+    fn wrapping_sub(self, rhs: Self) -> Self;
+    fn wrapping_mul(self, rhs: Self) -> Self;
+    fn saturating_add(self, rhs: Self) -> Self;
+    fn saturating_sub(self, rhs: Self) -> Self;
+    fn saturating_mul(self, rhs: Self) -> Self;
+    fn checked_add(self, rhs: Self) -> Option<Self>;
+    fn checked_sub(self, rhs: Self) -> Option<Self>;
+    fn checked_mul(self, rhs: Self) -> Option<Self>;
+}
+
+impl MazieNumeric for f64 {
+    fn zero() -> Self {
+        0.0
+    }
+
+    fn one() -> Self {
+        1.0
+    }
+
+    fn from_str_radix(s: &str, radix: u32) -> Result<Self, MazieParseError> {
+        if radix != 10 {
+            return Err(MazieParseError::UnsupportedRadix(radix));
+        }
+        s.parse::<f64>().map_err(|_| MazieParseError::Invalid)
+    }
+
+    fn infinity() -> Option<Self> {
+        Some(f64::INFINITY)
+    }
+
+    fn nan() -> Option<Self> {
+        Some(f64::NAN)
+    }
+
+    fn wrapping_add(self, rhs: Self) -> Self {
+        self + rhs
+    }
+
+    fn wrapping_sub(self, rhs: Self) -> Self {
+        self - rhs
+    }
+
+    fn wrapping_mul(self, rhs: Self) -> Self {
+        self * rhs
+    }
+
+    fn saturating_add(self, rhs: Self) -> Self {
+        self + rhs
+    }
+
+    fn saturating_sub(self, rhs: Self) -> Self {
+        self - rhs
+    }
+
+    fn saturating_mul(self, rhs: Self) -> Self {
+        self * rhs
+    }
+
+    fn checked_add(self, rhs: Self) -> Option<Self> {
+        Some(self + rhs)
+    }
+
+    fn checked_sub(self, rhs: Self) -> Option<Self> {
+        Some(self - rhs)
+    }
+
+    fn checked_mul(self, rhs: Self) -> Option<Self> {
+        Some(self * rhs)
+    }
+}
+
+impl MazieNumeric for i32 {
+    fn zero() -> Self {
+        0
+    }
+
+    fn one() -> Self {
+        1
+    }
+
+    fn from_str_radix(s: &str, radix: u32) -> Result<Self, MazieParseError> {
+        if !(2..=36).contains(&radix) {
+            return Err(MazieParseError::UnsupportedRadix(radix));
+        }
+        i32::from_str_radix(s, radix).map_err(|_| MazieParseError::Invalid)
+    }
+
+    fn infinity() -> Option<Self> {
+        None
+    }
+
+    fn nan() -> Option<Self> {
+        None
+    }
+
+    fn wrapping_add(self, rhs: Self) -> Self {
+        i32::wrapping_add(self, rhs)
+    }
+
+    fn wrapping_sub(self, rhs: Self) -> Self {
+        i32::wrapping_sub(self, rhs)
+    }
+
+    fn wrapping_mul(self, rhs: Self) -> Self {
+        i32::wrapping_mul(self, rhs)
+    }
+
+    fn saturating_add(self, rhs: Self) -> Self {
+        i32::saturating_add(self, rhs)
+    }
+
+    fn saturating_sub(self, rhs: Self) -> Self {
+        i32::saturating_sub(self, rhs)
+    }
+
+    fn saturating_mul(self, rhs: Self) -> Self {
+        i32::saturating_mul(self, rhs)
+    }
+
+    fn checked_add(self, rhs: Self) -> Option<Self> {
+        i32::checked_add(self, rhs)
+    }
+
+    fn checked_sub(self, rhs: Self) -> Option<Self> {
+        i32::checked_sub(self, rhs)
+    }
+
+    fn checked_mul(self, rhs: Self) -> Option<Self> {
+        i32::checked_mul(self, rhs)
+    }
+}
+
+impl MazieNumeric for i64 {
+    fn zero() -> Self {
+        0
+    }
+
+    fn one() -> Self {
+        1
+    }
+
+    fn from_str_radix(s: &str, radix: u32) -> Result<Self, MazieParseError> {
+        if !(2..=36).contains(&radix) {
+            return Err(MazieParseError::UnsupportedRadix(radix));
+        }
+        i64::from_str_radix(s, radix).map_err(|_| MazieParseError::Invalid)
+    }
+
+    fn infinity() -> Option<Self> {
+        None
+    }
+
+    fn nan() -> Option<Self> {
+        None
+    }
+
+    fn wrapping_add(self, rhs: Self) -> Self {
+        i64::wrapping_add(self, rhs)
+    }
+
+    fn wrapping_sub(self, rhs: Self) -> Self {
+        i64::wrapping_sub(self, rhs)
+    }
+
+    fn wrapping_mul(self, rhs: Self) -> Self {
+        i64::wrapping_mul(self, rhs)
+    }
+
+    fn saturating_add(self, rhs: Self) -> Self {
+        i64::saturating_add(self, rhs)
+    }
+
+    fn saturating_sub(self, rhs: Self) -> Self {
+        i64::saturating_sub(self, rhs)
+    }
+
+    fn saturating_mul(self, rhs: Self) -> Self {
+        i64::saturating_mul(self, rhs)
+    }
+
+    fn checked_add(self, rhs: Self) -> Option<Self> {
+        i64::checked_add(self, rhs)
+    }
+
+    fn checked_sub(self, rhs: Self) -> Option<Self> {
+        i64::checked_sub(self, rhs)
+    }
+
+    fn checked_mul(self, rhs: Self) -> Option<Self> {
+        i64::checked_mul(self, rhs)
+    }
+}